@@ -0,0 +1,116 @@
+/// BPSK pulse shape filter.
+///
+/// Used by [`BpskModParams::set_pulse_shape`].
+#[repr(u8)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum BpskPulseShape {
+    /// Sigfox-shaped filter.
+    ///
+    /// This is the pulse shape required to meet the Sigfox RF specification
+    /// for the ramp up/down of a BPSK burst.
+    Sigfox = 0x16,
+}
+
+/// BPSK modulation parameters.
+///
+/// This is an argument of [`set_bpsk_mod_params`].
+///
+/// [`set_bpsk_mod_params`]: crate::SubGhz::set_bpsk_mod_params
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BpskModParams {
+    buf: [u8; 5],
+}
+
+impl BpskModParams {
+    /// Create a new `BpskModParams` struct.
+    ///
+    /// This is the same as `default`, but in a `const` function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use subghz::BpskModParams;
+    ///
+    /// const BPSK_MOD_PARAMS: BpskModParams = BpskModParams::new();
+    /// ```
+    pub const fn new() -> BpskModParams {
+        BpskModParams {
+            buf: [
+                crate::OpCode::SetBpskModParams as u8,
+                0x00,
+                0x00,
+                0x00,
+                BpskPulseShape::Sigfox as u8,
+            ],
+        }
+    }
+
+    /// Set the bitrate.
+    ///
+    /// This uses the same encoding as the FSK bitrate:
+    ///
+    /// BR = 32 × 32e6 / bitrate
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use subghz::BpskModParams;
+    ///
+    /// // 100 bps Sigfox uplink
+    /// const BPSK_MOD_PARAMS: BpskModParams = BpskModParams::new().set_bitrate(100);
+    /// ```
+    #[must_use = "set_bitrate returns a new BpskModParams"]
+    pub const fn set_bitrate(mut self, bitrate: u32) -> BpskModParams {
+        let br: u32 = (((32 * 32_000_000) as u64) / (bitrate as u64)) as u32;
+        self.buf[1] = ((br >> 16) & 0xFF) as u8;
+        self.buf[2] = ((br >> 8) & 0xFF) as u8;
+        self.buf[3] = (br & 0xFF) as u8;
+        self
+    }
+
+    /// Set the pulse shape filter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use subghz::{BpskModParams, BpskPulseShape};
+    ///
+    /// const BPSK_MOD_PARAMS: BpskModParams =
+    ///     BpskModParams::new().set_pulse_shape(BpskPulseShape::Sigfox);
+    /// # assert_eq!(BPSK_MOD_PARAMS.as_slice()[4], 0x16);
+    /// ```
+    #[must_use = "set_pulse_shape returns a new BpskModParams"]
+    pub const fn set_pulse_shape(mut self, shape: BpskPulseShape) -> BpskModParams {
+        self.buf[4] = shape as u8;
+        self
+    }
+
+    /// Extracts a slice containing the packet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use subghz::BpskModParams;
+    ///
+    /// const BPSK_MOD_PARAMS: BpskModParams = BpskModParams::new();
+    /// assert_eq!(BPSK_MOD_PARAMS.as_slice().len(), 5);
+    /// ```
+    pub const fn as_slice(&self) -> &[u8] {
+        &self.buf
+    }
+}
+
+impl Default for BpskModParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::SubGhz {
+    /// Set the BPSK modulation parameters.
+    ///
+    /// See [`BpskModParams`] for more details.
+    pub fn set_bpsk_mod_params(&mut self, params: &BpskModParams) -> Result<(), crate::Error> {
+        self.write(params.as_slice())
+    }
+}
@@ -0,0 +1,76 @@
+/// BPSK packet parameters.
+///
+/// This is an argument of [`set_bpsk_packet_params`].
+///
+/// [`set_bpsk_packet_params`]: crate::SubGhz::set_bpsk_packet_params
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BpskPacketParams {
+    buf: [u8; 2],
+}
+
+impl BpskPacketParams {
+    /// Create a new `BpskPacketParams` struct.
+    ///
+    /// This is the same as `default`, but in a `const` function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use subghz::BpskPacketParams;
+    ///
+    /// const BPSK_PACKET_PARAMS: BpskPacketParams = BpskPacketParams::new();
+    /// ```
+    pub const fn new() -> BpskPacketParams {
+        BpskPacketParams {
+            buf: [crate::OpCode::SetPacketParams as u8, 0x00],
+        }
+    }
+
+    /// Set the payload length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use subghz::BpskPacketParams;
+    ///
+    /// const BPSK_PACKET_PARAMS: BpskPacketParams = BpskPacketParams::new().set_payload_len(12);
+    /// # assert_eq!(BPSK_PACKET_PARAMS.as_slice()[1], 12);
+    /// ```
+    #[must_use = "set_payload_len returns a new BpskPacketParams"]
+    pub const fn set_payload_len(mut self, len: u8) -> BpskPacketParams {
+        self.buf[1] = len;
+        self
+    }
+
+    /// Extracts a slice containing the packet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use subghz::BpskPacketParams;
+    ///
+    /// const BPSK_PACKET_PARAMS: BpskPacketParams = BpskPacketParams::new().set_payload_len(4);
+    /// assert_eq!(BPSK_PACKET_PARAMS.as_slice(), &[0x8C, 0x04]);
+    /// ```
+    pub const fn as_slice(&self) -> &[u8] {
+        &self.buf
+    }
+}
+
+impl Default for BpskPacketParams {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::SubGhz {
+    /// Set the BPSK packet parameters.
+    ///
+    /// See [`BpskPacketParams`] for more details.
+    pub fn set_bpsk_packet_params(
+        &mut self,
+        params: &BpskPacketParams,
+    ) -> Result<(), crate::Error> {
+        self.write(params.as_slice())
+    }
+}
@@ -0,0 +1,116 @@
+use crate::RfFreq;
+
+/// LoRaWAN region channel plan.
+///
+/// Turns a channel index into the [`RfFreq`] for that channel, so that
+/// LoRaWAN or other FHSS stacks do not need to hand-code frequency tables.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Region {
+    /// EU868, often used in Europe.
+    ///
+    /// Channels 0-2 are the default join channels (868.1, 868.3, 868.5 MHz).
+    /// Channels 3-7 extrapolate the same 200 kHz spacing and stay within the
+    /// 863-870 MHz ISM band, but real-world LoRaWAN deployments assign their
+    /// extended channels via `NewChannelReq` and are not required to follow
+    /// this linear ramp — treat channels 3-7 as a convenient default, not a
+    /// regulation-mandated plan.
+    Eu868,
+    /// US915, often used in Australia and North America.
+    ///
+    /// Channels 0-63 are the 125 kHz uplink channels spaced 200 kHz apart
+    /// starting at 902.3 MHz, and channels 64-71 are the 500 kHz uplink
+    /// channels spaced 1.6 MHz apart starting at 903.0 MHz.
+    Us915,
+}
+
+impl Region {
+    const EU868_UPLINK_BASE: u32 = 868_100_000;
+    const EU868_UPLINK_SPACING: u32 = 200_000;
+    const EU868_UPLINK_CHANNELS: u8 = 8;
+
+    const US915_UPLINK_125K_BASE: u32 = 902_300_000;
+    const US915_UPLINK_125K_SPACING: u32 = 200_000;
+    const US915_UPLINK_125K_CHANNELS: u8 = 64;
+
+    const US915_UPLINK_500K_BASE: u32 = 903_000_000;
+    const US915_UPLINK_500K_SPACING: u32 = 1_600_000;
+    const US915_UPLINK_500K_CHANNELS: u8 = 8;
+
+    const US915_DOWNLINK_BASE: u32 = 923_300_000;
+    const US915_DOWNLINK_SPACING: u32 = 600_000;
+    const US915_DOWNLINK_CHANNELS: u8 = 8;
+
+    /// Get the uplink frequency for a channel index.
+    ///
+    /// Returns `None` if the channel index is out of range for the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use subghz::{Region, RfFreq};
+    ///
+    /// assert_eq!(Region::Eu868.uplink(0), Some(RfFreq::from_frequency(868_100_000)));
+    /// assert_eq!(Region::Eu868.uplink(8), None);
+    ///
+    /// assert_eq!(Region::Us915.uplink(0), Some(RfFreq::from_frequency(902_300_000)));
+    /// assert_eq!(Region::Us915.uplink(64), Some(RfFreq::from_frequency(903_000_000)));
+    /// assert_eq!(Region::Us915.uplink(72), None);
+    /// ```
+    pub fn uplink(&self, channel: u8) -> Option<RfFreq> {
+        match self {
+            Region::Eu868 => {
+                if channel < Self::EU868_UPLINK_CHANNELS {
+                    let freq: u32 =
+                        Self::EU868_UPLINK_BASE + (channel as u32) * Self::EU868_UPLINK_SPACING;
+                    RfFreq::try_from_frequency(freq).ok()
+                } else {
+                    None
+                }
+            }
+            Region::Us915 => {
+                if channel < Self::US915_UPLINK_125K_CHANNELS {
+                    let freq: u32 = Self::US915_UPLINK_125K_BASE
+                        + (channel as u32) * Self::US915_UPLINK_125K_SPACING;
+                    Some(RfFreq::from_frequency(freq))
+                } else if channel
+                    < Self::US915_UPLINK_125K_CHANNELS + Self::US915_UPLINK_500K_CHANNELS
+                {
+                    let idx: u32 = (channel - Self::US915_UPLINK_125K_CHANNELS) as u32;
+                    let freq: u32 =
+                        Self::US915_UPLINK_500K_BASE + idx * Self::US915_UPLINK_500K_SPACING;
+                    Some(RfFreq::from_frequency(freq))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Get the downlink frequency for a channel index.
+    ///
+    /// Returns `None` if the channel index is out of range for the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use subghz::{Region, RfFreq};
+    ///
+    /// assert_eq!(Region::Us915.downlink(0), Some(RfFreq::from_frequency(923_300_000)));
+    /// assert_eq!(Region::Us915.downlink(8), None);
+    /// ```
+    pub fn downlink(&self, channel: u8) -> Option<RfFreq> {
+        match self {
+            // RX1 in EU868 reuses the uplink channel plan.
+            Region::Eu868 => self.uplink(channel),
+            Region::Us915 => {
+                if channel < Self::US915_DOWNLINK_CHANNELS {
+                    let freq: u32 =
+                        Self::US915_DOWNLINK_BASE + (channel as u32) * Self::US915_DOWNLINK_SPACING;
+                    Some(RfFreq::from_frequency(freq))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
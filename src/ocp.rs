@@ -1,13 +1,103 @@
+use crate::PaSel;
+
 /// Power amplifier over current protection.
 ///
 /// Used by [`set_pa_ocp`].
 ///
 /// [`set_pa_ocp`]: crate::SubGhz::set_pa_ocp
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
-#[repr(u8)]
-pub enum Ocp {
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Ocp {
+    bits: u8,
+}
+
+impl Ocp {
     /// Maximum 60mA current for LP PA mode.
-    Max60m = 0x18,
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use subghz::Ocp;
+    ///
+    /// assert_eq!(Ocp::MAX_60M.as_milliamps(), 60);
+    /// ```
+    pub const MAX_60M: Ocp = Ocp::from_milliamps(60);
+
     /// Maximum 140mA for HP PA mode.
-    Max140m = 0x38,
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use subghz::Ocp;
+    ///
+    /// assert_eq!(Ocp::MAX_140M.as_milliamps(), 140);
+    /// ```
+    pub const MAX_140M: Ocp = Ocp::from_milliamps(140);
+
+    /// Maximum representable value of the 6-bit OCP trim field, in 2.5 mA
+    /// steps.
+    const MAX_BITS: u8 = 0x3F;
+
+    /// Create a new `Ocp` from a current limit in milliamps.
+    ///
+    /// The OCP trim field has a resolution of 2.5mA, the requested current
+    /// is rounded to the nearest representable step and saturated at the
+    /// maximum the field can hold.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use subghz::Ocp;
+    ///
+    /// assert_eq!(Ocp::from_milliamps(60).as_milliamps(), 60);
+    /// assert_eq!(Ocp::from_milliamps(61).as_milliamps(), 60);
+    /// assert_eq!(Ocp::from_milliamps(0).as_milliamps(), 0);
+    /// ```
+    pub const fn from_milliamps(ma: u16) -> Ocp {
+        let steps: u32 = ((ma as u32) * 2 + 2) / 5;
+        let bits: u8 = if steps > Self::MAX_BITS as u32 {
+            Self::MAX_BITS
+        } else {
+            steps as u8
+        };
+        Ocp { bits }
+    }
+
+    /// Create a new `Ocp` from the raw 6-bit register value.
+    pub const fn from_bits(bits: u8) -> Ocp {
+        Ocp {
+            bits: bits & Self::MAX_BITS,
+        }
+    }
+
+    /// Get the over current protection threshold in milliamps.
+    pub const fn as_milliamps(&self) -> u16 {
+        ((self.bits as u32 * 5) / 2) as u16
+    }
+
+    /// Get the raw register value.
+    pub const fn as_bits(&self) -> u8 {
+        self.bits
+    }
+
+    /// Get the ST-recommended over current protection threshold for a given
+    /// power amplifier selection.
+    ///
+    /// This prevents mismatched OCP/PA combinations that either clip output
+    /// power or fail to protect the amplifier.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use subghz::{Ocp, PaSel};
+    ///
+    /// assert_eq!(Ocp::recommended(PaSel::Lp), Ocp::MAX_60M);
+    /// assert_eq!(Ocp::recommended(PaSel::Hp), Ocp::MAX_140M);
+    /// ```
+    pub const fn recommended(pa: PaSel) -> Ocp {
+        match pa {
+            PaSel::Lp => Ocp::MAX_60M,
+            PaSel::Hp => Ocp::MAX_140M,
+        }
+    }
 }
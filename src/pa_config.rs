@@ -4,11 +4,86 @@
 ///
 /// [`set_pa_config`]: crate::SubGhz::set_pa_config
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct PaConfig {
     buf: [u8; 5],
 }
 
 impl PaConfig {
+    /// Optimal settings for +22dBm output power with the high power PA.
+    ///
+    /// This is the ST datasheet "optimal matching" setting, it will not
+    /// over-stress the PA.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use subghz::PaConfig;
+    ///
+    /// const PA_CONFIG: PaConfig = PaConfig::HP_22_DBM;
+    /// assert_eq!(PA_CONFIG.as_slice()[1], 0x04);
+    /// assert_eq!(PA_CONFIG.as_slice()[2], 0x07);
+    /// ```
+    pub const HP_22_DBM: PaConfig = PaConfig::new()
+        .set_pa(PaSel::Hp)
+        .set_pa_duty_cycle(0x04)
+        .set_hp_max(0x07);
+
+    /// Optimal settings for +20dBm output power with the high power PA.
+    ///
+    /// This is the ST datasheet "optimal matching" setting, it will not
+    /// over-stress the PA.
+    pub const HP_20_DBM: PaConfig = PaConfig::new()
+        .set_pa(PaSel::Hp)
+        .set_pa_duty_cycle(0x03)
+        .set_hp_max(0x05);
+
+    /// Optimal settings for +17dBm output power with the high power PA.
+    ///
+    /// This is the ST datasheet "optimal matching" setting, it will not
+    /// over-stress the PA.
+    pub const HP_17_DBM: PaConfig = PaConfig::new()
+        .set_pa(PaSel::Hp)
+        .set_pa_duty_cycle(0x02)
+        .set_hp_max(0x03);
+
+    /// Optimal settings for +14dBm output power with the high power PA.
+    ///
+    /// This is the ST datasheet "optimal matching" setting, it will not
+    /// over-stress the PA.
+    pub const HP_14_DBM: PaConfig = PaConfig::new()
+        .set_pa(PaSel::Hp)
+        .set_pa_duty_cycle(0x02)
+        .set_hp_max(0x02);
+
+    /// Optimal settings for +15dBm output power with the low power PA.
+    ///
+    /// This is the ST datasheet "optimal matching" setting, it will not
+    /// over-stress the PA when the synthesis frequency is above 400 MHz.
+    /// Below 400 MHz its PaDutyCycle of 0x6 exceeds the LP PA limit of 0x4.
+    pub const LP_15_DBM: PaConfig = PaConfig::new()
+        .set_pa(PaSel::Lp)
+        .set_pa_duty_cycle(0x06)
+        .set_hp_max(0x00);
+
+    /// Optimal settings for +14dBm output power with the low power PA.
+    ///
+    /// This is the ST datasheet "optimal matching" setting, it will not
+    /// over-stress the PA.
+    pub const LP_14_DBM: PaConfig = PaConfig::new()
+        .set_pa(PaSel::Lp)
+        .set_pa_duty_cycle(0x04)
+        .set_hp_max(0x00);
+
+    /// Optimal settings for +10dBm output power with the low power PA.
+    ///
+    /// This is the ST datasheet "optimal matching" setting, it will not
+    /// over-stress the PA.
+    pub const LP_10_DBM: PaConfig = PaConfig::new()
+        .set_pa(PaSel::Lp)
+        .set_pa_duty_cycle(0x01)
+        .set_hp_max(0x00);
+
     /// Create a new `PaConfig` struct.
     ///
     /// This is the same as `default`, but in a `const` function.
@@ -35,9 +110,9 @@ impl PaConfig {
     /// # Caution
     ///
     /// The following restrictions must be observed to avoid over-stress on the PA:
-    /// * LP PA mode with synthesis frequency > 400 MHz, PaDutyCycle must be < 0x7.
-    /// * LP PA mode with synthesis frequency < 400 MHz, PaDutyCycle must be < 0x4.
-    /// * HP PA mode, PaDutyCycle must be < 0x4
+    /// * LP PA mode with synthesis frequency > 400 MHz, PaDutyCycle must be ≤ 0x7.
+    /// * LP PA mode with synthesis frequency < 400 MHz, PaDutyCycle must be ≤ 0x4.
+    /// * HP PA mode, PaDutyCycle must be ≤ 0x4
     ///
     /// # Example
     ///
@@ -119,6 +194,7 @@ impl Default for PaConfig {
 /// This is an argument of [`PaConfig::set_pa`].
 #[repr(u8)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum PaSel {
     /// High power amplifier.
     Hp = 0b0,
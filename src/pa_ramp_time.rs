@@ -0,0 +1,74 @@
+/// Power amplifier ramp time.
+///
+/// Controls how quickly the PA output ramps up or down at the start and end
+/// of a transmission. Shaping the ramp is required to meet the transmit mask
+/// of protocols such as Sigfox, where a BPSK burst must start and end
+/// smoothly.
+///
+/// Used by [`set_pa_ramp_up_time`] and [`set_pa_ramp_down_time`].
+///
+/// [`set_pa_ramp_up_time`]: crate::SubGhz::set_pa_ramp_up_time
+/// [`set_pa_ramp_down_time`]: crate::SubGhz::set_pa_ramp_down_time
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+#[repr(u8)]
+pub enum PaRampTime {
+    /// 10 us
+    Ramp10u = 0x0,
+    /// 20 us
+    Ramp20u = 0x1,
+    /// 40 us
+    Ramp40u = 0x2,
+    /// 80 us
+    Ramp80u = 0x3,
+    /// 200 us
+    Ramp200u = 0x4,
+    /// 800 us
+    Ramp800u = 0x5,
+    /// 1700 us
+    Ramp1700u = 0x6,
+    /// 3400 us
+    Ramp3400u = 0x7,
+}
+
+impl PaRampTime {
+    /// Get the ramp time in microseconds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use subghz::PaRampTime;
+    ///
+    /// assert_eq!(PaRampTime::Ramp10u.as_micros(), 10);
+    /// assert_eq!(PaRampTime::Ramp3400u.as_micros(), 3400);
+    /// ```
+    pub const fn as_micros(&self) -> u16 {
+        match self {
+            PaRampTime::Ramp10u => 10,
+            PaRampTime::Ramp20u => 20,
+            PaRampTime::Ramp40u => 40,
+            PaRampTime::Ramp80u => 80,
+            PaRampTime::Ramp200u => 200,
+            PaRampTime::Ramp800u => 800,
+            PaRampTime::Ramp1700u => 1700,
+            PaRampTime::Ramp3400u => 3400,
+        }
+    }
+}
+
+impl Default for PaRampTime {
+    fn default() -> Self {
+        PaRampTime::Ramp10u
+    }
+}
+
+impl crate::SubGhz {
+    /// Set the PA ramp-up time, shaping the start of the transmit burst.
+    pub fn set_pa_ramp_up_time(&mut self, ramp: PaRampTime) -> Result<(), crate::Error> {
+        self.write(&[crate::OpCode::SetPaRampUpTime as u8, ramp as u8])
+    }
+
+    /// Set the PA ramp-down time, shaping the end of the transmit burst.
+    pub fn set_pa_ramp_down_time(&mut self, ramp: PaRampTime) -> Result<(), crate::Error> {
+        self.write(&[crate::OpCode::SetPaRampDownTime as u8, ramp as u8])
+    }
+}
@@ -1,3 +1,26 @@
+/// Error returned by [`RfFreq::try_from_frequency`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct FreqError {
+    freq: u32,
+}
+
+impl FreqError {
+    /// Get the frequency that caused the error.
+    pub const fn freq(&self) -> u32 {
+        self.freq
+    }
+}
+
+impl core::fmt::Display for FreqError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{} Hz is outside of the usable 150 MHz to 960 MHz range",
+            self.freq
+        )
+    }
+}
+
 /// RF frequency structure.
 ///
 /// This is an argument of [`set_rf_frequency`].
@@ -8,6 +31,13 @@ pub struct RfFreq {
     buf: [u8; 5],
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for RfFreq {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(fmt, "RfFreq {{ freq: {=u32} Hz }}", self.freq())
+    }
+}
+
 impl RfFreq {
     /// 915MHz, often used in Australia and North America.
     ///
@@ -86,6 +116,54 @@ impl RfFreq {
         Self::from_bits((((freq as u64) * (1 << 25)) / 32_000_000) as u32)
     }
 
+    /// Lowest frequency supported by the radio, in hertz.
+    pub const MIN_FREQ: u32 = 150_000_000;
+
+    /// Highest frequency supported by the radio, in hertz.
+    pub const MAX_FREQ: u32 = 960_000_000;
+
+    /// Create a new `RfFreq` from a PLL frequency, rejecting frequencies
+    /// outside of the radio's usable 150 MHz to 960 MHz ISM range.
+    ///
+    /// This routes through the same arithmetic as [`from_frequency`], but
+    /// validates the requested frequency first.
+    ///
+    /// [`from_frequency`]: RfFreq::from_frequency
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use subghz::RfFreq;
+    ///
+    /// assert!(RfFreq::try_from_frequency(915_000_000).is_ok());
+    /// assert!(RfFreq::try_from_frequency(100_000_000).is_err());
+    /// assert!(RfFreq::try_from_frequency(1_000_000_000).is_err());
+    /// ```
+    pub fn try_from_frequency(freq: u32) -> Result<RfFreq, FreqError> {
+        if freq < Self::MIN_FREQ || freq > Self::MAX_FREQ {
+            Err(FreqError { freq })
+        } else {
+            Ok(Self::from_frequency(freq))
+        }
+    }
+
+    /// Get the signed error introduced by the register's quantization,
+    /// relative to the requested frequency.
+    ///
+    /// A positive value indicates the actual frequency is higher than
+    /// requested, a negative value indicates it is lower.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use subghz::RfFreq;
+    ///
+    /// assert_eq!(RfFreq::from_frequency(915_000_000).freq_error(915_000_000), 0);
+    /// ```
+    pub fn freq_error(&self, requested: u32) -> i32 {
+        (self.freq() as i64 - requested as i64) as i32
+    }
+
     // Get the frequency bit value.
     const fn as_bits(&self) -> u32 {
         ((self.buf[1] as u32) << 24)
@@ -0,0 +1,38 @@
+use subghz::{Region, RfFreq};
+
+#[test]
+fn us915_125k_500k_boundary() {
+    // Channel 63 is the last 125 kHz uplink channel.
+    assert_eq!(
+        Region::Us915.uplink(63),
+        Some(RfFreq::from_frequency(902_300_000 + 63 * 200_000))
+    );
+    // Channel 64 is the first 500 kHz uplink channel.
+    assert_eq!(
+        Region::Us915.uplink(64),
+        Some(RfFreq::from_frequency(903_000_000))
+    );
+}
+
+#[test]
+fn us915_uplink_out_of_range() {
+    // Channel 71 is the last 500 kHz uplink channel.
+    assert!(Region::Us915.uplink(71).is_some());
+    assert_eq!(Region::Us915.uplink(72), None);
+}
+
+#[test]
+fn us915_downlink_out_of_range() {
+    assert!(Region::Us915.downlink(7).is_some());
+    assert_eq!(Region::Us915.downlink(8), None);
+}
+
+#[test]
+fn eu868_uplink_stays_in_band() {
+    for channel in 0..8 {
+        let rf_freq: RfFreq = Region::Eu868.uplink(channel).unwrap();
+        assert!(rf_freq.freq() >= 863_000_000);
+        assert!(rf_freq.freq() <= 870_000_000);
+    }
+    assert_eq!(Region::Eu868.uplink(8), None);
+}
@@ -0,0 +1,27 @@
+use subghz::{Ocp, PaSel};
+
+#[test]
+fn from_milliamps_rounds_to_nearest_step() {
+    assert_eq!(Ocp::from_milliamps(60).as_milliamps(), 60);
+    assert_eq!(Ocp::from_milliamps(61).as_milliamps(), 60);
+    assert_eq!(Ocp::from_milliamps(63).as_milliamps(), 62);
+}
+
+#[test]
+fn from_milliamps_saturates_at_register_max() {
+    // The 6-bit OCP trim field saturates at 0x3F steps (157.5 mA).
+    assert_eq!(Ocp::from_milliamps(1000).as_bits(), 0x3F);
+    assert_eq!(Ocp::from_milliamps(1000).as_milliamps(), 157);
+}
+
+#[test]
+fn named_constants_match_datasheet_values() {
+    assert_eq!(Ocp::MAX_60M.as_bits(), 0x18);
+    assert_eq!(Ocp::MAX_140M.as_bits(), 0x38);
+}
+
+#[test]
+fn recommended_matches_pa_selection() {
+    assert_eq!(Ocp::recommended(PaSel::Lp), Ocp::MAX_60M);
+    assert_eq!(Ocp::recommended(PaSel::Hp), Ocp::MAX_140M);
+}
@@ -9,3 +9,24 @@ fn max() {
 fn min() {
     assert_eq!(RfFreq::from_bits(u32::MIN).freq(), 0);
 }
+
+#[test]
+fn try_from_frequency_accepts_band_limits() {
+    assert!(RfFreq::try_from_frequency(150_000_000).is_ok());
+    assert!(RfFreq::try_from_frequency(960_000_000).is_ok());
+}
+
+#[test]
+fn try_from_frequency_rejects_outside_band() {
+    assert!(RfFreq::try_from_frequency(149_999_999).is_err());
+    assert!(RfFreq::try_from_frequency(960_000_001).is_err());
+}
+
+#[test]
+fn freq_error_reports_quantization_error() {
+    // 868_100_000 Hz is not exactly representable by the PLL step, so the
+    // resolved frequency differs slightly from the requested one.
+    let rf_freq: RfFreq = RfFreq::from_frequency(868_100_000);
+    let error: i32 = rf_freq.freq_error(868_100_000);
+    assert_eq!(rf_freq.freq() as i64 - 868_100_000i64, error as i64);
+}